@@ -1,14 +1,18 @@
-use num::{BigInt, FromPrimitive};
+use num::pow::Pow;
+use num::{BigInt, FromPrimitive, Integer, Signed, ToPrimitive, Zero};
 use std::error::Error;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
 use std::str::FromStr;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParseWenyanIntError {
-    kind: WenyanIntErrorKind,
+    pub(crate) kind: WenyanIntErrorKind,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-enum WenyanIntErrorKind {
+pub(crate) enum WenyanIntErrorKind {
     Empty,
     InvalidDigit,
     RedundantSign,
@@ -36,6 +40,229 @@ impl Error for ParseWenyanIntError {
     }
 }
 
+/// Returns the digit (0-9) a 零..九 character represents, if `c` is one of those characters.
+fn digit_value(c: char) -> Option<u8> {
+    "零一二三四五六七八九"
+        .chars()
+        .position(|chr| chr == c)
+        .map(|d| d as u8)
+}
+
+/// Returns the magnitude of a small (in-section) multiplier character: 十/百/千.
+fn small_mult(c: char) -> Option<u32> {
+    match c {
+        '十' => Some(10),
+        '百' => Some(100),
+        '千' => Some(1000),
+        _ => None,
+    }
+}
+
+/// Parses an unsigned classical numeral (no leading 負) into its magnitude, implementing the
+/// place-value grammar shared by `WenyanInt::from_str` and the integer/fractional components of
+/// `WenyanReal::from_str`.
+///
+/// 萬 and 億 are not interchangeable "big multiplier" tokens scaling everything seen so far —
+/// 億 (10^8) is the outer, "chunk" boundary and 萬 (10^4) only ever rescales the chunk since the
+/// last 億 (or the start of the numeral). So this keeps three accumulators: `total` holds
+/// whole 億-chunks already folded in, `wan_section` holds the current chunk's value above the
+/// 萬 place, and `section` holds the current 千/百/十 group below it. Seeing 萬 folds `section`
+/// into `wan_section` scaled by 10^4; seeing 億 folds `wan_section` and `section` into `total`
+/// scaled by 10^8 and starts a fresh chunk — which is what lets "一億二千三百四十五萬六千七百
+/// 八十九" (123,456,789) round-trip instead of re-scaling the already-億-scaled `total` by 萬.
+pub(crate) fn parse_magnitude(s: &str) -> Result<BigInt, ParseWenyanIntError> {
+    if s.is_empty() {
+        return Err(ParseWenyanIntError {
+            kind: WenyanIntErrorKind::Empty,
+        });
+    }
+    if s == "零" {
+        return Ok(BigInt::from(0));
+    }
+
+    let mut total = BigInt::from(0);
+    let mut wan_section = BigInt::from(0);
+    let mut section = BigInt::from(0);
+    let mut current: u8 = 0;
+    // Whether `current` holds a bare digit that hasn't yet been folded in by a multiplier, so
+    // that e.g. "一二" (two bare digits in a row) is rejected rather than silently keeping the
+    // last one.
+    let mut pending_digit = false;
+    // Magnitude of the last multiplier token consumed, reset whenever a digit is seen. Used to
+    // reject two multipliers of the same magnitude appearing back to back, and to ensure 零 only
+    // ever appears as a gap right after a multiplier (never leading, or right after a bare
+    // digit).
+    let mut last_mult: Option<u64> = None;
+
+    for c in s.chars() {
+        if c == '零' {
+            if last_mult.is_none() {
+                return Err(ParseWenyanIntError {
+                    kind: WenyanIntErrorKind::InvalidDigit,
+                });
+            }
+            continue;
+        } else if let Some(d) = digit_value(c) {
+            if pending_digit {
+                return Err(ParseWenyanIntError {
+                    kind: WenyanIntErrorKind::InvalidDigit,
+                });
+            }
+            current = d;
+            pending_digit = true;
+            last_mult = None;
+        } else if let Some(mult) = small_mult(c) {
+            if last_mult == Some(mult as u64) {
+                return Err(ParseWenyanIntError {
+                    kind: WenyanIntErrorKind::InvalidDigit,
+                });
+            }
+            let factor = if current == 0 { 1 } else { current };
+            section += BigInt::from(factor) * BigInt::from(mult);
+            current = 0;
+            pending_digit = false;
+            last_mult = Some(mult as u64);
+        } else if c == '萬' {
+            if last_mult == Some(10_000) {
+                return Err(ParseWenyanIntError {
+                    kind: WenyanIntErrorKind::InvalidDigit,
+                });
+            }
+            wan_section += (section + BigInt::from(current)) * BigInt::from(10_000u32);
+            section = BigInt::from(0);
+            current = 0;
+            pending_digit = false;
+            last_mult = Some(10_000);
+        } else if c == '億' {
+            if last_mult == Some(100_000_000) {
+                return Err(ParseWenyanIntError {
+                    kind: WenyanIntErrorKind::InvalidDigit,
+                });
+            }
+            total = (total + wan_section + section + BigInt::from(current))
+                * BigInt::from(100_000_000u64);
+            wan_section = BigInt::from(0);
+            section = BigInt::from(0);
+            current = 0;
+            pending_digit = false;
+            last_mult = Some(100_000_000);
+        } else {
+            return Err(ParseWenyanIntError {
+                kind: WenyanIntErrorKind::InvalidDigit,
+            });
+        }
+    }
+
+    total += wan_section + section + BigInt::from(current);
+    Ok(total)
+}
+
+/// Renders `n` (0..=9999) as a 千/百/十 section, omitting a trailing digit where 零 is
+/// implied by the repo's `from_str` grammar. `leading` allows the bare "十" form (rather than
+/// "一十") when this section is the very first one of the whole number.
+fn section_to_string(n: u16, leading: bool) -> String {
+    if n == 0 {
+        return String::new();
+    }
+    let digits = [
+        (n / 1000 % 10) as u8,
+        (n / 100 % 10) as u8,
+        (n / 10 % 10) as u8,
+        (n % 10) as u8,
+    ];
+    let suffixes = ['千', '百', '十'];
+    let mut out = String::new();
+    let mut started = false;
+    let mut pending_zero = false;
+    for (i, &d) in digits.iter().enumerate() {
+        if d == 0 {
+            if started {
+                pending_zero = true;
+            }
+            continue;
+        }
+        if pending_zero {
+            out.push('零');
+            pending_zero = false;
+        }
+        let omit_digit = leading && !started && i == 2 && d == 1;
+        if !omit_digit {
+            out.push("零一二三四五六七八九".chars().nth(d as usize).unwrap());
+        }
+        if i < 3 {
+            out.push(suffixes[i]);
+        }
+        started = true;
+    }
+    out
+}
+
+/// Renders `n` (0..=99_999_999, i.e. below the 億 boundary) as an optional 萬-scaled high group
+/// followed by the low (below-萬) group, mirroring the two accumulators `parse_magnitude` keeps
+/// per 億-chunk. `leading` is forwarded to the first non-empty group's `section_to_string` call.
+fn render_below_yi(n: u32, leading: bool) -> String {
+    let high = (n / 10_000) as u16;
+    let low = (n % 10_000) as u16;
+    if high == 0 {
+        return section_to_string(low, leading);
+    }
+    let mut out = section_to_string(high, leading);
+    out.push('萬');
+    if low != 0 {
+        if low < 1000 {
+            out.push('零');
+        }
+        out.push_str(&section_to_string(low, false));
+    }
+    out
+}
+
+/// Renders the absolute value `n` in classical numerals, the inverse of `parse_magnitude`.
+///
+/// `parse_magnitude` only ever folds a 萬-scaled group into the 億-chunk it belongs to, and folds
+/// whole 億-chunks into `total` one at a time — it never lets one 萬 rescale an already-億-scaled
+/// total. So rendering mirrors that: split `n` into the 億-chunk count (`n / 1e8`, rendered
+/// recursively since it may itself need its own 億) and the remainder below 1e8 (rendered via
+/// `render_below_yi`), joined by a single 億 and a 零 gap if the remainder doesn't reach its own
+/// leading (千萬) digit.
+///
+/// A value whose 億-chunk count `n / 1e8` is itself a nonzero multiple of 10^8 (e.g. 10^16,
+/// rendered "一億億", or 10^16 + 5, rendered "一億億零五") gets two adjacent 億, which `parse_magnitude`
+/// rejects as back-to-back multipliers of the same magnitude; such values do not round-trip.
+fn format_abs(n: &BigInt) -> String {
+    format_abs_with_leading(n, true)
+}
+
+fn format_abs_with_leading(n: &BigInt, leading: bool) -> String {
+    let yi = BigInt::from(100_000_000u64);
+    if n < &yi {
+        return render_below_yi(n.to_u32().unwrap(), leading);
+    }
+    let chunk_count = n / &yi;
+    let remainder = n % &yi;
+    let mut out = format_abs_with_leading(&chunk_count, leading);
+    out.push('億');
+    if !remainder.is_zero() {
+        if remainder < BigInt::from(10_000_000u32) {
+            out.push('零');
+        }
+        out.push_str(&render_below_yi(remainder.to_u32().unwrap(), false));
+    }
+    out
+}
+
+impl std::fmt::Display for WenyanInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.data.is_zero() {
+            return write!(f, "零");
+        }
+        if self.data.is_negative() {
+            write!(f, "負")?;
+        }
+        write!(f, "{}", format_abs(&self.data.abs()))
+    }
+}
+
 #[derive(Debug)]
 pub struct WenyanInt {
     data: BigInt,
@@ -54,53 +281,290 @@ impl PartialEq for WenyanInt {
 impl FromStr for WenyanInt {
     type Err = ParseWenyanIntError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut result_vec: Vec<u8> = vec![];
-        let mut chars = s.chars();
+        if s.is_empty() {
+            return Err(Self::Err {
+                kind: WenyanIntErrorKind::Empty,
+            });
+        }
+
         let mut sign = 1;
-        loop {
-            match chars.next() {
-                Some(c) => {
-                    if c == '負' {
-                        if sign == -1 {
-                            return Err(Self::Err {
-                                kind: WenyanIntErrorKind::RedundantSign,
-                            });
-                        }
-                        sign = -1;
-                    } else if "零一二三四五六七八九".contains(c) {
-                        match "零一二三四五六七八九".chars().position(|chr| chr == c) {
-                            Some(0) => {
-                                if result_vec.len() == 0 {
-                                    if chars.next().is_some() {
-                                        return Err(Self::Err {
-                                            kind: WenyanIntErrorKind::InvalidDigit,
-                                        });
-                                    }
-                                    result_vec.push(48);
-                                }
-                            }
-                            Some(d) => {
-                                result_vec.push(d as u8 + 48);
-                            }
-                            None => {}
-                        }
-                    } else if "十".contains(c) {
-                    } else {
-                        return Err(Self::Err {
-                            kind: WenyanIntErrorKind::InvalidDigit,
-                        });
-                    }
-                }
-                None => break,
-            }
+        let mut rest = s;
+        let mut sign_count = 0;
+        while let Some(stripped) = rest.strip_prefix('負') {
+            sign_count += 1;
+            rest = stripped;
         }
-        result_vec.reverse();
+        if sign_count >= 2 {
+            return Err(Self::Err {
+                kind: WenyanIntErrorKind::RedundantSign,
+            });
+        }
+        if sign_count == 1 {
+            sign = -1;
+        }
+        if rest.is_empty() {
+            // Input was just "負" with no digits.
+            return Err(Self::Err {
+                kind: WenyanIntErrorKind::Empty,
+            });
+        }
+
+        let magnitude = parse_magnitude(rest)?;
         Ok(WenyanInt {
-            data: BigInt::parse_bytes(&result_vec, 10).unwrap() * sign,
+            data: magnitude * sign,
         })
     }
 }
 
+impl From<BigInt> for WenyanInt {
+    fn from(data: BigInt) -> Self {
+        WenyanInt { data }
+    }
+}
+
+impl Add for WenyanInt {
+    type Output = WenyanInt;
+    fn add(self, rhs: WenyanInt) -> WenyanInt {
+        WenyanInt {
+            data: self.data + rhs.data,
+        }
+    }
+}
+
+impl AddAssign for WenyanInt {
+    fn add_assign(&mut self, rhs: WenyanInt) {
+        self.data += rhs.data;
+    }
+}
+
+impl Sub for WenyanInt {
+    type Output = WenyanInt;
+    fn sub(self, rhs: WenyanInt) -> WenyanInt {
+        WenyanInt {
+            data: self.data - rhs.data,
+        }
+    }
+}
+
+impl SubAssign for WenyanInt {
+    fn sub_assign(&mut self, rhs: WenyanInt) {
+        self.data -= rhs.data;
+    }
+}
+
+impl Mul for WenyanInt {
+    type Output = WenyanInt;
+    fn mul(self, rhs: WenyanInt) -> WenyanInt {
+        WenyanInt {
+            data: self.data * rhs.data,
+        }
+    }
+}
+
+impl MulAssign for WenyanInt {
+    fn mul_assign(&mut self, rhs: WenyanInt) {
+        self.data *= rhs.data;
+    }
+}
+
+impl Div for WenyanInt {
+    type Output = WenyanInt;
+    fn div(self, rhs: WenyanInt) -> WenyanInt {
+        WenyanInt {
+            data: self.data / rhs.data,
+        }
+    }
+}
+
+impl DivAssign for WenyanInt {
+    fn div_assign(&mut self, rhs: WenyanInt) {
+        self.data /= rhs.data;
+    }
+}
+
+impl Rem for WenyanInt {
+    type Output = WenyanInt;
+    fn rem(self, rhs: WenyanInt) -> WenyanInt {
+        WenyanInt {
+            data: self.data % rhs.data,
+        }
+    }
+}
+
+impl RemAssign for WenyanInt {
+    fn rem_assign(&mut self, rhs: WenyanInt) {
+        self.data %= rhs.data;
+    }
+}
+
+impl Neg for WenyanInt {
+    type Output = WenyanInt;
+    fn neg(self) -> WenyanInt {
+        WenyanInt { data: -self.data }
+    }
+}
+
+impl WenyanInt {
+    /// Parses the longest valid numeral prefix of `s`, returning the parsed value together with
+    /// the unconsumed remainder. Unlike `from_str`, which requires the whole string to be a
+    /// single numeral, this is meant for a tokenizer scanning a larger buffer that doesn't
+    /// already know where the numeral ends.
+    pub fn parse_prefix(s: &str) -> Result<(WenyanInt, &str), ParseWenyanIntError> {
+        let mut boundaries: Vec<usize> = s.char_indices().map(|(i, _)| i).skip(1).collect();
+        boundaries.push(s.len());
+
+        let mut longest = None;
+        for &end in &boundaries {
+            if WenyanInt::from_str(&s[..end]).is_ok() {
+                longest = Some(end);
+            }
+        }
+
+        match longest {
+            Some(end) => Ok((WenyanInt::from_str(&s[..end])?, &s[end..])),
+            None => WenyanInt::from_str(s).map(|value| (value, "")),
+        }
+    }
+
+    /// Divides `self` by `other`, returning `(quotient, remainder)` in one pass, mirroring
+    /// `num::Integer::div_rem`.
+    pub fn div_rem(&self, other: &WenyanInt) -> (WenyanInt, WenyanInt) {
+        let (q, r) = self.data.div_rem(&other.data);
+        (WenyanInt { data: q }, WenyanInt { data: r })
+    }
+
+    /// Greatest common divisor of `self` and `other`.
+    pub fn gcd(&self, other: &WenyanInt) -> WenyanInt {
+        WenyanInt {
+            data: self.data.gcd(&other.data),
+        }
+    }
+
+    /// Raises `self` to the `exp` power.
+    pub fn pow(&self, exp: u32) -> WenyanInt {
+        WenyanInt {
+            data: Pow::pow(self.data.clone(), exp),
+        }
+    }
+
+    /// The lower and upper bounds (inclusive) of a two's-complement signed integer `bits` wide.
+    fn signed_bounds(bits: u32) -> (BigInt, BigInt) {
+        let half = BigInt::from(1) << (bits - 1);
+        (-&half, half - 1)
+    }
+
+    /// Wraps `n` into the range of a two's-complement signed integer `bits` wide, mirroring how
+    /// the built-in integer types behave under `wrapping_*` arithmetic.
+    fn wrap_to_bits(n: &BigInt, bits: u32) -> BigInt {
+        let modulus = BigInt::from(1) << bits;
+        let half = BigInt::from(1) << (bits - 1);
+        let mut wrapped = n.mod_floor(&modulus);
+        if wrapped >= half {
+            wrapped -= modulus;
+        }
+        wrapped
+    }
+
+    /// Adds `self` and `other`, returning `None` if the exact result doesn't fit in a signed
+    /// integer `bits` wide.
+    pub fn checked_add(&self, other: &WenyanInt, bits: u32) -> Option<WenyanInt> {
+        Self::checked_from(&self.data + &other.data, bits)
+    }
+
+    /// Subtracts `other` from `self`, returning `None` on `bits`-wide overflow.
+    pub fn checked_sub(&self, other: &WenyanInt, bits: u32) -> Option<WenyanInt> {
+        Self::checked_from(&self.data - &other.data, bits)
+    }
+
+    /// Multiplies `self` and `other`, returning `None` on `bits`-wide overflow.
+    pub fn checked_mul(&self, other: &WenyanInt, bits: u32) -> Option<WenyanInt> {
+        Self::checked_from(&self.data * &other.data, bits)
+    }
+
+    /// Divides `self` by `other`, returning `None` on division by zero or `bits`-wide overflow.
+    pub fn checked_div(&self, other: &WenyanInt, bits: u32) -> Option<WenyanInt> {
+        if other.data.is_zero() {
+            return None;
+        }
+        Self::checked_from(&self.data / &other.data, bits)
+    }
+
+    /// Remainder of `self / other`, returning `None` on division by zero or `bits`-wide overflow.
+    pub fn checked_rem(&self, other: &WenyanInt, bits: u32) -> Option<WenyanInt> {
+        if other.data.is_zero() {
+            return None;
+        }
+        Self::checked_from(&self.data % &other.data, bits)
+    }
+
+    fn checked_from(data: BigInt, bits: u32) -> Option<WenyanInt> {
+        let (min, max) = Self::signed_bounds(bits);
+        if data >= min && data <= max {
+            Some(WenyanInt { data })
+        } else {
+            None
+        }
+    }
+
+    /// Adds `self` and `other`, wrapping around on `bits`-wide overflow.
+    pub fn wrapping_add(&self, other: &WenyanInt, bits: u32) -> WenyanInt {
+        WenyanInt {
+            data: Self::wrap_to_bits(&(&self.data + &other.data), bits),
+        }
+    }
+
+    /// Subtracts `other` from `self`, wrapping around on `bits`-wide overflow.
+    pub fn wrapping_sub(&self, other: &WenyanInt, bits: u32) -> WenyanInt {
+        WenyanInt {
+            data: Self::wrap_to_bits(&(&self.data - &other.data), bits),
+        }
+    }
+
+    /// Multiplies `self` and `other`, wrapping around on `bits`-wide overflow.
+    pub fn wrapping_mul(&self, other: &WenyanInt, bits: u32) -> WenyanInt {
+        WenyanInt {
+            data: Self::wrap_to_bits(&(&self.data * &other.data), bits),
+        }
+    }
+
+    /// Divides `self` by `other`, wrapping around on `bits`-wide overflow. Panics if `other` is
+    /// zero, mirroring the standard library's `wrapping_div`.
+    pub fn wrapping_div(&self, other: &WenyanInt, bits: u32) -> WenyanInt {
+        WenyanInt {
+            data: Self::wrap_to_bits(&(&self.data / &other.data), bits),
+        }
+    }
+
+    /// Remainder of `self / other`, wrapping around on `bits`-wide overflow. Panics if `other`
+    /// is zero, mirroring the standard library's `wrapping_rem`.
+    pub fn wrapping_rem(&self, other: &WenyanInt, bits: u32) -> WenyanInt {
+        WenyanInt {
+            data: Self::wrap_to_bits(&(&self.data % &other.data), bits),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for WenyanInt {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for WenyanInt {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 impl FromPrimitive for WenyanInt {
     fn from_i64(n: i64) -> Option<Self> {
         Some(WenyanInt {
@@ -133,3 +597,254 @@ fn test_from_str() {
         WenyanInt::from_i32(-1).unwrap()
     );
 }
+
+#[test]
+fn test_from_str_place_value() {
+    assert_eq!(
+        WenyanInt::from_str("十").unwrap(),
+        WenyanInt::from_i32(10).unwrap()
+    );
+    assert_eq!(
+        WenyanInt::from_str("二十").unwrap(),
+        WenyanInt::from_i32(20).unwrap()
+    );
+    assert_eq!(
+        WenyanInt::from_str("三百二十一").unwrap(),
+        WenyanInt::from_i32(321).unwrap()
+    );
+    assert_eq!(
+        WenyanInt::from_str("一千零一").unwrap(),
+        WenyanInt::from_i32(1001).unwrap()
+    );
+    assert_eq!(
+        WenyanInt::from_str("一萬").unwrap(),
+        WenyanInt::from_i32(10_000).unwrap()
+    );
+    assert_eq!(
+        WenyanInt::from_str("一億").unwrap(),
+        WenyanInt::from_i64(100_000_000).unwrap()
+    );
+    assert_eq!(
+        WenyanInt::from_str("負三百二十一").unwrap(),
+        WenyanInt::from_i32(-321).unwrap()
+    );
+}
+
+#[test]
+fn test_arithmetic_ops() {
+    let three = WenyanInt::from_i32(3).unwrap();
+    let four = WenyanInt::from_i32(4).unwrap();
+    assert_eq!(
+        WenyanInt::from_i32(3).unwrap() + WenyanInt::from_i32(4).unwrap(),
+        WenyanInt::from_i32(7).unwrap()
+    );
+    assert_eq!(
+        WenyanInt::from_i32(3).unwrap() - WenyanInt::from_i32(4).unwrap(),
+        WenyanInt::from_i32(-1).unwrap()
+    );
+    assert_eq!(
+        WenyanInt::from_i32(3).unwrap() * WenyanInt::from_i32(4).unwrap(),
+        WenyanInt::from_i32(12).unwrap()
+    );
+    assert_eq!(
+        WenyanInt::from_i32(13).unwrap() / WenyanInt::from_i32(4).unwrap(),
+        WenyanInt::from_i32(3).unwrap()
+    );
+    assert_eq!(
+        WenyanInt::from_i32(13).unwrap() % WenyanInt::from_i32(4).unwrap(),
+        WenyanInt::from_i32(1).unwrap()
+    );
+    assert_eq!(
+        -WenyanInt::from_i32(3).unwrap(),
+        WenyanInt::from_i32(-3).unwrap()
+    );
+
+    let mut acc = WenyanInt::from_i32(1).unwrap();
+    acc += WenyanInt::from_i32(2).unwrap();
+    assert_eq!(acc, WenyanInt::from_i32(3).unwrap());
+
+    assert_eq!(
+        WenyanInt::from_i32(13).unwrap().div_rem(&four),
+        (
+            WenyanInt::from_i32(3).unwrap(),
+            WenyanInt::from_i32(1).unwrap()
+        )
+    );
+    assert_eq!(
+        WenyanInt::from_i32(12)
+            .unwrap()
+            .gcd(&WenyanInt::from_i32(18).unwrap()),
+        WenyanInt::from_i32(6).unwrap()
+    );
+    assert_eq!(three.pow(4), WenyanInt::from_i32(81).unwrap());
+}
+
+#[test]
+fn test_checked_and_wrapping_ops() {
+    let max_i8 = WenyanInt::from_i32(127).unwrap();
+    let one = WenyanInt::from_i32(1).unwrap();
+    assert_eq!(max_i8.checked_add(&one, 8), None);
+    assert_eq!(
+        max_i8.checked_sub(&one, 8),
+        Some(WenyanInt::from_i32(126).unwrap())
+    );
+    assert_eq!(
+        max_i8.wrapping_add(&one, 8),
+        WenyanInt::from_i32(-128).unwrap()
+    );
+
+    let min_i8 = WenyanInt::from_i32(-128).unwrap();
+    assert_eq!(
+        min_i8.checked_mul(&WenyanInt::from_i32(-1).unwrap(), 8),
+        None
+    );
+    assert_eq!(
+        min_i8.wrapping_mul(&WenyanInt::from_i32(-1).unwrap(), 8),
+        min_i8
+    );
+
+    assert_eq!(
+        WenyanInt::from_i32(10)
+            .unwrap()
+            .checked_div(&WenyanInt::from_i32(0).unwrap(), 8),
+        None
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_roundtrip() {
+    let x = WenyanInt::from_str("三百二十一").unwrap();
+    let json = serde_json::to_string(&x).unwrap();
+    assert_eq!(json, "\"三百二十一\"");
+    assert_eq!(serde_json::from_str::<WenyanInt>(&json).unwrap(), x);
+
+    let err = serde_json::from_str::<WenyanInt>("\"\"").unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("cannot parse integer from empty string"));
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(WenyanInt::from_i32(0).unwrap().to_string(), "零");
+    assert_eq!(WenyanInt::from_i32(1).unwrap().to_string(), "一");
+    assert_eq!(WenyanInt::from_i32(10).unwrap().to_string(), "十");
+    assert_eq!(WenyanInt::from_i32(20).unwrap().to_string(), "二十");
+    assert_eq!(WenyanInt::from_i32(321).unwrap().to_string(), "三百二十一");
+    assert_eq!(WenyanInt::from_i32(1001).unwrap().to_string(), "一千零一");
+    assert_eq!(
+        WenyanInt::from_i32(-321).unwrap().to_string(),
+        "負三百二十一"
+    );
+    assert_eq!(WenyanInt::from_i32(10_000).unwrap().to_string(), "一萬");
+    assert_eq!(
+        WenyanInt::from_i64(100_000_000).unwrap().to_string(),
+        "一億"
+    );
+    // A nonzero 萬 place alongside a nonzero 億 place must render as exactly one 億, with the
+    // 萬-scaled group nested below it rather than spelled out as a second 萬.
+    assert_eq!(
+        WenyanInt::from_i64(123_456_789).unwrap().to_string(),
+        "一億二千三百四十五萬六千七百八十九"
+    );
+    assert_eq!(
+        WenyanInt::from_str("一億二千三百四十五萬六千七百八十九").unwrap(),
+        WenyanInt::from_i64(123_456_789).unwrap()
+    );
+    // 10^12 has no dedicated unit in this grammar, so it's spelled as ten thousand 億.
+    assert_eq!(
+        WenyanInt::from_i64(1_000_000_000_000).unwrap().to_string(),
+        "一萬億"
+    );
+}
+
+#[test]
+fn test_display_roundtrip() {
+    for n in [
+        0,
+        1,
+        9,
+        10,
+        11,
+        20,
+        100,
+        1001,
+        10_000,
+        10_001,
+        20_000,
+        100_000_000,
+        100_000_001,
+        123_456_789,
+        -321,
+    ] {
+        let x = WenyanInt::from_i64(n).unwrap();
+        assert_eq!(WenyanInt::from_str(&x.to_string()).unwrap(), x);
+    }
+
+    let x = WenyanInt::from(BigInt::from(1_000_000_000_000i64));
+    assert_eq!(WenyanInt::from_str(&x.to_string()).unwrap(), x);
+}
+
+#[test]
+fn test_parse_prefix() {
+    assert_eq!(
+        WenyanInt::parse_prefix("三百二十一甲").unwrap(),
+        (WenyanInt::from_i32(321).unwrap(), "甲")
+    );
+    assert_eq!(
+        WenyanInt::parse_prefix("一萬").unwrap(),
+        (WenyanInt::from_i32(10_000).unwrap(), "")
+    );
+    assert_eq!(
+        WenyanInt::parse_prefix("十十").unwrap(),
+        (WenyanInt::from_i32(10).unwrap(), "十")
+    );
+    assert_eq!(
+        WenyanInt::parse_prefix("負三").unwrap(),
+        (WenyanInt::from_i32(-3).unwrap(), "")
+    );
+    assert_eq!(
+        WenyanInt::parse_prefix("").unwrap_err(),
+        ParseWenyanIntError {
+            kind: WenyanIntErrorKind::Empty
+        }
+    );
+}
+
+#[test]
+fn test_from_str_errors() {
+    assert_eq!(
+        WenyanInt::from_str("").unwrap_err(),
+        ParseWenyanIntError {
+            kind: WenyanIntErrorKind::Empty
+        }
+    );
+    assert_eq!(
+        WenyanInt::from_str("負負一").unwrap_err(),
+        ParseWenyanIntError {
+            kind: WenyanIntErrorKind::RedundantSign
+        }
+    );
+    assert_eq!(
+        WenyanInt::from_str("十十").unwrap_err(),
+        ParseWenyanIntError {
+            kind: WenyanIntErrorKind::InvalidDigit
+        }
+    );
+    // A bare digit run with no multiplier in between is malformed, not "last digit wins".
+    assert_eq!(
+        WenyanInt::from_str("一二三").unwrap_err(),
+        ParseWenyanIntError {
+            kind: WenyanIntErrorKind::InvalidDigit
+        }
+    );
+    // 零 is only a gap filler right after a multiplier; a leading 零 before more digits follow
+    // has nothing to fill a gap in.
+    assert_eq!(
+        WenyanInt::from_str("零一").unwrap_err(),
+        ParseWenyanIntError {
+            kind: WenyanIntErrorKind::InvalidDigit
+        }
+    );
+}
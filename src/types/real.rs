@@ -0,0 +1,159 @@
+use num::rational::BigRational;
+use num::BigInt;
+use std::str::FromStr;
+
+use crate::types::int::{parse_magnitude, ParseWenyanIntError, WenyanIntErrorKind, WenyanInt};
+
+/// A 文言 fractional/decimal value, exact and arbitrary-precision via `num::BigRational`.
+///
+/// 文言 spells fractions as "X分之Y" (Y parts of X, i.e. Y/X) and mixed numbers as
+/// "N又X分之Y" (N plus Y/X), so `WenyanReal` keeps the value as a rational rather than a
+/// lossy float.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WenyanReal {
+    data: BigRational,
+}
+
+impl FromStr for WenyanReal {
+    type Err = ParseWenyanIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(Self::Err {
+                kind: WenyanIntErrorKind::Empty,
+            });
+        }
+
+        let mut sign = 1;
+        let mut rest = s;
+        let mut sign_count = 0;
+        while let Some(stripped) = rest.strip_prefix('負') {
+            sign_count += 1;
+            rest = stripped;
+        }
+        if sign_count >= 2 {
+            return Err(Self::Err {
+                kind: WenyanIntErrorKind::RedundantSign,
+            });
+        }
+        if sign_count == 1 {
+            sign = -1;
+        }
+        if rest.is_empty() {
+            return Err(Self::Err {
+                kind: WenyanIntErrorKind::Empty,
+            });
+        }
+
+        let (integer_part, fraction_part) = match rest.split_once('又') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (rest, None),
+        };
+
+        let mut data = match fraction_part {
+            Some(fraction) => {
+                let integer = parse_magnitude(integer_part)?;
+                BigRational::from_integer(integer) + parse_fraction(fraction)?
+            }
+            // No 又: either a bare fraction ("X分之Y") or a bare integer.
+            None if integer_part.contains("分之") => parse_fraction(integer_part)?,
+            None => BigRational::from_integer(parse_magnitude(integer_part)?),
+        };
+
+        if sign == -1 {
+            data = -data;
+        }
+        Ok(WenyanReal { data })
+    }
+}
+
+/// Parses the "X分之Y" divisor form (Y parts of X, i.e. Y/X) shared by bare and mixed fractions.
+fn parse_fraction(s: &str) -> Result<BigRational, ParseWenyanIntError> {
+    let (denominator, numerator) = s.split_once("分之").ok_or(ParseWenyanIntError {
+        kind: WenyanIntErrorKind::InvalidDigit,
+    })?;
+    let denominator = parse_magnitude(denominator)?;
+    let numerator = parse_magnitude(numerator)?;
+    if denominator == BigInt::from(0) {
+        return Err(ParseWenyanIntError {
+            kind: WenyanIntErrorKind::InvalidDigit,
+        });
+    }
+    Ok(BigRational::new(numerator, denominator))
+}
+
+impl std::fmt::Display for WenyanReal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut data = self.data.clone();
+        if data.is_integer() {
+            return write!(f, "{}", WenyanInt::from(data.to_integer()));
+        }
+        if data < BigRational::from_integer(BigInt::from(0)) {
+            write!(f, "負")?;
+            data = -data;
+        }
+        let whole = data.to_integer();
+        let fraction = data - BigRational::from_integer(whole.clone());
+        if whole != BigInt::from(0) {
+            write!(f, "{}又", WenyanInt::from(whole))?;
+        }
+        write!(
+            f,
+            "{}分之{}",
+            WenyanInt::from(fraction.denom().clone()),
+            WenyanInt::from(fraction.numer().clone())
+        )
+    }
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!(
+        WenyanReal::from_str("二分之一").unwrap(),
+        WenyanReal {
+            data: BigRational::new(BigInt::from(1), BigInt::from(2)),
+        }
+    );
+    assert_eq!(
+        WenyanReal::from_str("三又二分之一").unwrap(),
+        WenyanReal {
+            data: BigRational::new(BigInt::from(7), BigInt::from(2)),
+        }
+    );
+    assert_eq!(
+        WenyanReal::from_str("五").unwrap(),
+        WenyanReal {
+            data: BigRational::from_integer(BigInt::from(5)),
+        }
+    );
+    assert_eq!(
+        WenyanReal::from_str("負三又二分之一").unwrap(),
+        WenyanReal {
+            data: BigRational::new(BigInt::from(-7), BigInt::from(2)),
+        }
+    );
+}
+
+#[test]
+fn test_from_str_errors() {
+    assert_eq!(
+        WenyanReal::from_str("").unwrap_err(),
+        ParseWenyanIntError {
+            kind: WenyanIntErrorKind::Empty
+        }
+    );
+    assert_eq!(
+        WenyanReal::from_str("零分之一").unwrap_err(),
+        ParseWenyanIntError {
+            kind: WenyanIntErrorKind::InvalidDigit
+        }
+    );
+}
+
+#[test]
+fn test_display_roundtrip() {
+    for s in ["五", "二分之一", "三又二分之一", "負三又二分之一"] {
+        let x = WenyanReal::from_str(s).unwrap();
+        assert_eq!(x.to_string(), s);
+        assert_eq!(WenyanReal::from_str(&x.to_string()).unwrap(), x);
+    }
+}